@@ -1,9 +1,17 @@
 use chrono::{DateTime, Local};
 use eframe::egui;
 use human_bytes::human_bytes;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use path_absolutize::Absolutize;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+// Coalesce bursts of filesystem events into a single refresh.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
 
 struct FileExplorer {
     current_path: PathBuf,
@@ -12,11 +20,234 @@ struct FileExplorer {
     selected_entry: Option<usize>,
     path_to_navigate: Option<PathBuf>,
     needs_repaint: bool,
+    search_input: String,
+    virtual_root: Option<PathBuf>,
+    watcher: Option<RecommendedWatcher>,
+    watcher_rx: Option<mpsc::Receiver<notify::Result<Event>>>,
+    pending_watch_event: Option<Instant>,
+    tree_mode: bool,
+    tree_root: Option<TreeNode>,
+    renaming: Option<(PathBuf, String)>,
+    clipboard: Option<(PathBuf, ClipOp)>,
+    confirmation: Option<NeedConfirmation>,
+    bookmarks: Vec<PathBuf>,
+    well_known_locations: Vec<(&'static str, PathBuf)>,
+    show_hidden: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct AppConfig {
+    bookmarks: Vec<PathBuf>,
+    #[serde(default)]
+    show_hidden: bool,
+}
+
+fn app_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("file-explorer").join("config.json"))
+}
+
+// Pre-chunk0-7 bookmarks were persisted here; kept around only so existing
+// pins can be migrated into the merged config file below.
+fn legacy_bookmarks_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("file-explorer").join("bookmarks.json"))
+}
+
+#[derive(Deserialize)]
+struct LegacyBookmarksConfig {
+    bookmarks: Vec<PathBuf>,
+}
+
+fn load_app_config() -> AppConfig {
+    if let Some(path) = app_config_path() {
+        if let Ok(contents) = fs::read_to_string(&path) {
+            if let Ok(config) = serde_json::from_str(&contents) {
+                return config;
+            }
+        }
+    }
+
+    // No config.json yet: fall back to the old bookmarks.json once so
+    // anyone who pinned bookmarks before chunk0-7 doesn't lose them.
+    if let Some(legacy_path) = legacy_bookmarks_config_path() {
+        if let Ok(contents) = fs::read_to_string(&legacy_path) {
+            if let Ok(legacy) = serde_json::from_str::<LegacyBookmarksConfig>(&contents) {
+                return AppConfig {
+                    bookmarks: legacy.bookmarks,
+                    show_hidden: false,
+                };
+            }
+        }
+    }
+
+    AppConfig::default()
+}
+
+fn detect_well_known_locations() -> Vec<(&'static str, PathBuf)> {
+    let candidates: [(&'static str, Option<PathBuf>); 3] = [
+        ("Home", dirs::home_dir()),
+        ("Desktop", dirs::desktop_dir()),
+        ("Downloads", dirs::download_dir()),
+    ];
+
+    candidates
+        .into_iter()
+        .filter_map(|(label, path)| path.map(|path| (label, path)))
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ClipOp {
+    Copy,
+    Move,
+}
+
+#[derive(Clone)]
+enum NeedConfirmation {
+    Delete(PathBuf),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FileType {
+    File,
+    Folder,
+    Root,
+}
+
+// A single row in the collapsible tree view. Children are loaded lazily the
+// first time a folder is expanded, so opening a deep subtree doesn't walk it
+// up front.
+struct TreeNode {
+    path: PathBuf,
+    file_type: FileType,
+    expanded: bool,
+    children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    fn root(path: PathBuf) -> Self {
+        let mut node = Self {
+            path,
+            file_type: FileType::Root,
+            expanded: true,
+            children: Vec::new(),
+        };
+        node.load_children();
+        node
+    }
+
+    fn child(path: PathBuf) -> Self {
+        let file_type = if path.is_dir() {
+            FileType::Folder
+        } else {
+            FileType::File
+        };
+        Self {
+            path,
+            file_type,
+            expanded: false,
+            children: Vec::new(),
+        }
+    }
+
+    fn load_children(&mut self) {
+        if self.file_type == FileType::File {
+            return;
+        }
+
+        self.children.clear();
+        match fs::read_dir(&self.path) {
+            Ok(entries) => {
+                for entry in entries.flatten() {
+                    self.children.push(TreeNode::child(entry.path()));
+                }
+                // Directories first, then files, both alphabetically.
+                self.children.sort_by(|a, b| {
+                    let a_is_dir = a.file_type == FileType::Folder;
+                    let b_is_dir = b.file_type == FileType::Folder;
+
+                    if a_is_dir && !b_is_dir {
+                        std::cmp::Ordering::Less
+                    } else if !a_is_dir && b_is_dir {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        a.path.file_name().cmp(&b.path.file_name())
+                    }
+                });
+            }
+            Err(e) => {
+                eprintln!("Error reading directory {:?}: {}", self.path, e);
+            }
+        }
+    }
+
+    fn toggle(&mut self) {
+        self.expanded = !self.expanded;
+        if self.expanded && self.children.is_empty() {
+            self.load_children();
+        }
+    }
+}
+
+// Renders a tree node and, recursively, its expanded children. Any failures
+// from double-clicking a file are appended to `errors` for the caller to
+// surface through `error_message`.
+fn render_tree_node(
+    ui: &mut egui::Ui,
+    node: &mut TreeNode,
+    depth: usize,
+    errors: &mut Vec<String>,
+) {
+    ui.horizontal(|ui| {
+        ui.add_space(depth as f32 * 16.0);
+
+        match node.file_type {
+            FileType::Folder | FileType::Root => {
+                let arrow = if node.expanded {
+                    "\u{25bc}"
+                } else {
+                    "\u{25b6}"
+                };
+                let name = if node.file_type == FileType::Root {
+                    node.path.to_string_lossy().to_string()
+                } else {
+                    node.path
+                        .file_name()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .to_string()
+                };
+                if ui.button(format!("{} \u{1F4C1} {}", arrow, name)).clicked() {
+                    node.toggle();
+                }
+            }
+            FileType::File => {
+                let name = node
+                    .path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+                let resp = ui.selectable_label(false, name);
+                if resp.double_clicked() {
+                    if let Err(e) = open_file_with_default_app(&node.path) {
+                        errors.push(e);
+                    }
+                }
+            }
+        }
+    });
+
+    if node.expanded {
+        for child in &mut node.children {
+            render_tree_node(ui, child, depth + 1, errors);
+        }
+    }
 }
 
 impl Default for FileExplorer {
     fn default() -> Self {
         let current_path = std::env::current_dir().unwrap_or_default();
+        let config = load_app_config();
         let mut app = Self {
             current_path,
             entries: Vec::new(),
@@ -24,12 +255,43 @@ impl Default for FileExplorer {
             selected_entry: None,
             path_to_navigate: None,
             needs_repaint: false,
+            search_input: String::new(),
+            virtual_root: None,
+            watcher: None,
+            watcher_rx: None,
+            pending_watch_event: None,
+            tree_mode: false,
+            tree_root: None,
+            renaming: None,
+            clipboard: None,
+            confirmation: None,
+            bookmarks: config.bookmarks,
+            well_known_locations: detect_well_known_locations(),
+            show_hidden: config.show_hidden,
         };
         app.refresh_entries();
         app
     }
 }
 
+fn is_hidden(path: &Path) -> bool {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+        if let Ok(metadata) = fs::metadata(path) {
+            if metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0 {
+                return true;
+            }
+        }
+    }
+
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.starts_with('.'))
+        .unwrap_or(false)
+}
+
 fn open_file_with_default_app(path: &Path) -> Result<(), String> {
     let command = if cfg!(target_os = "windows") {
         Command::new("cmd")
@@ -59,15 +321,21 @@ impl FileExplorer {
             Ok(entries) => {
                 self.error_message = None;
 
-                // Add parent directory (..) unless we're at the root
-                if self.current_path.parent().is_some() {
+                // Add parent directory (..) unless we're at the root, or at the
+                // virtual root (the sandbox boundary should not be escapable)
+                let at_virtual_root =
+                    self.virtual_root.as_deref() == Some(self.current_path.as_path());
+                if self.current_path.parent().is_some() && !at_virtual_root {
                     self.entries.push(self.current_path.join(".."));
                 }
 
-                // Add all entries in the current directory
+                // Add all entries in the current directory, respecting show_hidden
                 for entry in entries {
                     if let Ok(entry) = entry {
-                        self.entries.push(entry.path());
+                        let path = entry.path();
+                        if self.show_hidden || !is_hidden(&path) {
+                            self.entries.push(path);
+                        }
                     }
                 }
 
@@ -91,12 +359,21 @@ impl FileExplorer {
         }
     }
 
-    fn navigate_to(&mut self, path: PathBuf) {
+    fn navigate_to(&mut self, ctx: &egui::Context, path: PathBuf) {
         // Handle ".." (parent directory) specially
         if path.ends_with("..") {
+            if self.virtual_root.as_deref() == Some(self.current_path.as_path()) {
+                eprintln!(
+                    "Refusing to navigate above virtual root {:?}",
+                    self.current_path
+                );
+                return;
+            }
             if let Some(parent) = self.current_path.parent() {
                 self.current_path = parent.to_path_buf();
+                self.tree_root = None;
                 self.refresh_entries();
+                self.watch_current_path(ctx);
                 self.needs_repaint = true;
                 eprintln!("Navigated to parent: {:?}", self.current_path);
             }
@@ -111,8 +388,34 @@ impl FileExplorer {
         );
 
         if path.is_dir() {
-            self.current_path = path;
+            // Resolve `..`/`.` components without touching the filesystem so a
+            // sandboxed root can't be escaped via a crafted relative path.
+            let resolved = match path.absolutize() {
+                Ok(resolved) => resolved.into_owned(),
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to resolve path {:?}: {}", path, e));
+                    return;
+                }
+            };
+
+            if let Some(root) = &self.virtual_root {
+                if !resolved.starts_with(root) {
+                    self.error_message = Some(format!(
+                        "Cannot navigate outside of sandbox root {:?}",
+                        root
+                    ));
+                    eprintln!(
+                        "Rejected navigation to {:?}: outside of {:?}",
+                        resolved, root
+                    );
+                    return;
+                }
+            }
+
+            self.current_path = resolved;
+            self.tree_root = None;
             self.refresh_entries();
+            self.watch_current_path(ctx);
             self.needs_repaint = true;
             eprintln!("Successfully navigated to: {:?}", self.current_path);
         } else {
@@ -120,6 +423,63 @@ impl FileExplorer {
         }
     }
 
+    // (Re-)point the background watcher at `current_path`, replacing any previous one.
+    fn watch_current_path(&mut self, ctx: &egui::Context) {
+        self.watcher = None;
+        self.watcher_rx = None;
+        self.pending_watch_event = None;
+
+        let (tx, rx) = mpsc::channel();
+        let repaint_ctx = ctx.clone();
+        let mut watcher = match notify::recommended_watcher(move |res| {
+            let _ = tx.send(res);
+            // Wake eframe up even if the window is otherwise idle, so the
+            // debounced refresh in `poll_watcher` actually gets a chance to run.
+            repaint_ctx.request_repaint();
+        }) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                eprintln!("Failed to create filesystem watcher: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = watcher.watch(&self.current_path, RecursiveMode::NonRecursive) {
+            eprintln!("Failed to watch {:?}: {}", self.current_path, e);
+            return;
+        }
+
+        self.watcher = Some(watcher);
+        self.watcher_rx = Some(rx);
+    }
+
+    // Drain queued watcher events and, once the burst has quieted down, refresh.
+    fn poll_watcher(&mut self) {
+        let Some(rx) = &self.watcher_rx else {
+            return;
+        };
+
+        let mut saw_event = false;
+        while let Ok(res) = rx.try_recv() {
+            match res {
+                Ok(_event) => saw_event = true,
+                Err(e) => eprintln!("Watcher error: {}", e),
+            }
+        }
+
+        if saw_event {
+            self.pending_watch_event = Some(Instant::now());
+        }
+
+        if let Some(last_seen) = self.pending_watch_event {
+            if last_seen.elapsed() >= WATCH_DEBOUNCE {
+                self.pending_watch_event = None;
+                self.refresh_entries();
+                self.needs_repaint = true;
+            }
+        }
+    }
+
     fn get_file_info(&self, path: &Path) -> (String, String) {
         let size;
         let modified;
@@ -144,6 +504,128 @@ impl FileExplorer {
 
         (size, modified)
     }
+
+    fn rename_entry(&mut self, old_path: &Path, new_name: &str) {
+        // `new_name` is free-form user text: reject anything that isn't a plain
+        // file name (no separators, no `..`) so it can't be used to rename a
+        // file out of the current directory (or out of the virtual root).
+        let has_separator = new_name.contains(std::path::MAIN_SEPARATOR) || new_name.contains('/');
+        if new_name.is_empty() || has_separator || new_name == "." || new_name == ".." {
+            self.error_message = Some(format!("Invalid name: {:?}", new_name));
+            return;
+        }
+
+        let new_path = self.current_path.join(new_name);
+        if let Some(root) = &self.virtual_root {
+            if !new_path.starts_with(root) {
+                self.error_message =
+                    Some(format!("Cannot rename outside of sandbox root {:?}", root));
+                return;
+            }
+        }
+
+        match fs::rename(old_path, &new_path) {
+            Ok(()) => {
+                self.selected_entry = None;
+                self.refresh_entries();
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to rename {:?}: {}", old_path, e));
+            }
+        }
+    }
+
+    fn delete_entry(&mut self, path: &Path) {
+        let result = if path.is_dir() {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_file(path)
+        };
+
+        match result {
+            Ok(()) => {
+                self.selected_entry = None;
+                self.refresh_entries();
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to delete {:?}: {}", path, e));
+            }
+        }
+    }
+
+    fn create_directory(&mut self) {
+        let mut name = "New Folder".to_string();
+        let mut suffix = 1;
+        while self.current_path.join(&name).exists() {
+            suffix += 1;
+            name = format!("New Folder ({})", suffix);
+        }
+
+        let new_dir = self.current_path.join(&name);
+        match fs::create_dir(&new_dir) {
+            Ok(()) => {
+                self.refresh_entries();
+                self.renaming = Some((new_dir, name));
+            }
+            Err(e) => {
+                self.error_message =
+                    Some(format!("Failed to create directory {:?}: {}", new_dir, e));
+            }
+        }
+    }
+
+    fn paste_clipboard(&mut self) {
+        let Some((src, op)) = self.clipboard.clone() else {
+            return;
+        };
+        let Some(file_name) = src.file_name() else {
+            return;
+        };
+
+        let dest = self.current_path.join(file_name);
+        let result = match op {
+            ClipOp::Copy => fs::copy(&src, &dest).map(|_| ()),
+            ClipOp::Move => fs::rename(&src, &dest),
+        };
+
+        match result {
+            Ok(()) => {
+                // Only clear the clipboard once the paste actually succeeded, so a
+                // failed copy/move (permission error, collision, ...) doesn't lose
+                // the user's cut/copy and force them to redo it.
+                self.clipboard = None;
+                self.selected_entry = None;
+                self.refresh_entries();
+            }
+            Err(e) => self.error_message = Some(format!("Failed to paste {:?}: {}", src, e)),
+        }
+    }
+
+    fn save_config(&self) {
+        let Some(path) = app_config_path() else {
+            return;
+        };
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                eprintln!("Failed to create config directory {:?}: {}", parent, e);
+                return;
+            }
+        }
+
+        let config = AppConfig {
+            bookmarks: self.bookmarks.clone(),
+            show_hidden: self.show_hidden,
+        };
+        match serde_json::to_string_pretty(&config) {
+            Ok(json) => {
+                if let Err(e) = fs::write(&path, json) {
+                    eprintln!("Failed to save config to {:?}: {}", path, e);
+                }
+            }
+            Err(e) => eprintln!("Failed to serialize config: {}", e),
+        }
+    }
 }
 
 impl eframe::App for FileExplorer {
@@ -154,6 +636,9 @@ impl eframe::App for FileExplorer {
         self.error_message = None;
         self.selected_entry = None;
         self.path_to_navigate = None;
+        self.watcher = None;
+        self.watcher_rx = None;
+        self.save_config();
         // Explicitly drop any remaining Wayland resources
         let _ = gl;
     }
@@ -161,7 +646,13 @@ impl eframe::App for FileExplorer {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Handle navigation from previous frame (to avoid borrow issues)
         if let Some(path) = self.path_to_navigate.take() {
-            self.navigate_to(path);
+            self.navigate_to(ctx, path);
+        }
+
+        self.poll_watcher();
+        if self.pending_watch_event.is_some() {
+            // Keep ticking until the debounce window closes so queued events get flushed.
+            ctx.request_repaint_after(WATCH_DEBOUNCE);
         }
 
         // Check if we need to repaint after navigation
@@ -170,6 +661,53 @@ impl eframe::App for FileExplorer {
             ctx.request_repaint();
         }
 
+        egui::SidePanel::left("bookmarks_panel").show(ctx, |ui| {
+            ui.heading("Bookmarks");
+
+            let mut navigate_to = None;
+
+            ui.label("Locations");
+            for (label, path) in &self.well_known_locations {
+                if ui.selectable_label(false, *label).clicked() {
+                    navigate_to = Some(path.clone());
+                }
+            }
+
+            ui.separator();
+            ui.label("Pinned");
+            let mut remove_index = None;
+            for (i, bookmark) in self.bookmarks.iter().enumerate() {
+                let name = bookmark.to_string_lossy().to_string();
+                let resp = ui.selectable_label(false, &name);
+                if resp.clicked() {
+                    navigate_to = Some(bookmark.clone());
+                }
+                resp.context_menu(|ui| {
+                    if ui.button("Remove bookmark").clicked() {
+                        remove_index = Some(i);
+                        ui.close_menu();
+                    }
+                });
+            }
+            if let Some(i) = remove_index {
+                self.bookmarks.remove(i);
+                self.save_config();
+            }
+
+            ui.separator();
+            if ui.button("Add current folder").clicked()
+                && !self.bookmarks.contains(&self.current_path)
+            {
+                self.bookmarks.push(self.current_path.clone());
+                self.save_config();
+            }
+
+            if let Some(path) = navigate_to {
+                self.path_to_navigate = Some(path);
+                ctx.request_repaint();
+            }
+        });
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("File Explorer");
 
@@ -184,12 +722,126 @@ impl eframe::App for FileExplorer {
                 ui.colored_label(egui::Color32::RED, error);
             }
 
+            // Filter box: substring match (case-insensitive) against the file name
+            ui.horizontal(|ui| {
+                ui.label("Filter:");
+                let resp = ui.text_edit_singleline(&mut self.search_input);
+                if resp.changed() {
+                    self.selected_entry = None;
+                }
+
+                ui.checkbox(&mut self.tree_mode, "Tree view");
+                // `navigate_to` clears `tree_root` whenever `current_path` changes, so
+                // re-rooting here (rather than only on the false->true toggle) keeps the
+                // tree in sync with navigation that happens while Tree view is active.
+                if self.tree_mode && self.tree_root.is_none() {
+                    self.tree_root = Some(TreeNode::root(self.current_path.clone()));
+                }
+
+                if ui.checkbox(&mut self.show_hidden, "Show hidden").changed() {
+                    self.refresh_entries();
+                    self.save_config();
+                }
+            });
+
+            // File operation toolbar: new directory and paste (copy/move) from clipboard
+            ui.horizontal(|ui| {
+                if ui.button("New Folder").clicked() {
+                    self.create_directory();
+                }
+
+                let paste_label = match &self.clipboard {
+                    Some((path, ClipOp::Copy)) => {
+                        format!("Paste (copy {:?})", path.file_name().unwrap_or_default())
+                    }
+                    Some((path, ClipOp::Move)) => {
+                        format!("Paste (move {:?})", path.file_name().unwrap_or_default())
+                    }
+                    None => "Paste".to_string(),
+                };
+                if ui
+                    .add_enabled(self.clipboard.is_some(), egui::Button::new(paste_label))
+                    .clicked()
+                {
+                    self.paste_clipboard();
+                }
+            });
+
+            // Keybindings: F2 rename, Delete prompts confirmation
+            if let Some(idx) = self.selected_entry {
+                if let Some(entry) = self.entries.get(idx).cloned() {
+                    if ui.input(|i| i.key_pressed(egui::Key::F2)) {
+                        let name = entry
+                            .file_name()
+                            .unwrap_or_default()
+                            .to_string_lossy()
+                            .to_string();
+                        self.renaming = Some((entry.clone(), name));
+                    }
+                    if ui.input(|i| i.key_pressed(egui::Key::Delete)) {
+                        self.confirmation = Some(NeedConfirmation::Delete(entry));
+                    }
+                }
+            }
+
+            // Confirmation modal so a stray keypress can't trigger a recursive delete
+            if let Some(NeedConfirmation::Delete(path)) = self.confirmation.clone() {
+                egui::Window::new("Confirm delete")
+                    .collapsible(false)
+                    .resizable(false)
+                    .show(ui.ctx(), |ui| {
+                        ui.label(format!("Delete {:?}? This cannot be undone.", path));
+                        ui.horizontal(|ui| {
+                            if ui.button("Delete").clicked() {
+                                self.delete_entry(&path);
+                                self.confirmation = None;
+                            }
+                            if ui.button("Cancel").clicked() {
+                                self.confirmation = None;
+                            }
+                        });
+                    });
+            }
+
+            if self.tree_mode {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    if let Some(root) = &mut self.tree_root {
+                        let mut errors = Vec::new();
+                        render_tree_node(ui, root, 0, &mut errors);
+                        if let Some(e) = errors.into_iter().next() {
+                            self.error_message = Some(e);
+                        }
+                    }
+                });
+                return;
+            }
+
+            let search = self.search_input.to_lowercase();
+            // Collect owned paths (not `&self.entries` borrows) so the loop below is
+            // free to call `&mut self` methods such as `rename_entry`.
+            let visible_entries: Vec<(usize, PathBuf)> = self
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| {
+                    entry.ends_with("..")
+                        || search.is_empty()
+                        || entry
+                            .file_name()
+                            .unwrap_or_default()
+                            .to_string_lossy()
+                            .to_lowercase()
+                            .contains(&search)
+                })
+                .map(|(idx, entry)| (idx, entry.clone()))
+                .collect();
+
             // File/directory list
             egui::ScrollArea::vertical().show(ui, |ui| {
                 // Store paths that need navigation to avoid borrow issues
                 let mut clicked_path = None;
 
-                for (idx, entry) in self.entries.iter().enumerate() {
+                for (idx, entry) in visible_entries {
                     let is_parent_dir = entry.ends_with("..");
                     let is_dir = is_parent_dir || entry.is_dir();
 
@@ -203,29 +855,75 @@ impl eframe::App for FileExplorer {
                             .to_string()
                     };
 
-                    let (size, modified) = self.get_file_info(entry);
+                    let (size, modified) = self.get_file_info(&entry);
+                    let is_renaming = self
+                        .renaming
+                        .as_ref()
+                        .is_some_and(|(path, _)| path == &entry);
 
                     ui.horizontal(|ui| {
+                        if is_renaming {
+                            let (_, name) = self.renaming.as_mut().unwrap();
+                            let resp = ui.text_edit_singleline(name);
+                            resp.request_focus();
+                            if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                                let (path, name) = self.renaming.take().unwrap();
+                                self.rename_entry(&path, &name);
+                            } else if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                                self.renaming = None;
+                            }
+                            return;
+                        }
+
                         // For directories, make it more obvious it's clickable
                         let label_text = if is_dir {
                             format!("ðŸ“ {}", display_name)
                         } else {
-                            display_name
+                            display_name.clone()
                         };
 
                         // Use a button for directories and selectable for files
-                        if is_dir {
-                            if ui.button(&label_text).clicked() {
+                        let resp = if is_dir {
+                            let resp = ui.button(&label_text);
+                            if resp.clicked() {
                                 eprintln!("Directory button clicked: {:?}", entry);
                                 clicked_path = Some(entry.clone());
                             }
+                            resp
                         } else {
-                            let resp = ui.selectable_value(&mut self.selected_entry, Some(idx), &label_text);
+                            let resp = ui.selectable_value(
+                                &mut self.selected_entry,
+                                Some(idx),
+                                &label_text,
+                            );
                             if resp.double_clicked() {
                                 if let Err(e) = open_file_with_default_app(&entry.clone()) {
                                     self.error_message = Some(e.clone());
                                 }
                             }
+                            resp
+                        };
+
+                        if !is_parent_dir {
+                            resp.context_menu(|ui| {
+                                if ui.button("Rename").clicked() {
+                                    self.renaming = Some((entry.clone(), display_name.clone()));
+                                    ui.close_menu();
+                                }
+                                if ui.button("Delete").clicked() {
+                                    self.confirmation =
+                                        Some(NeedConfirmation::Delete(entry.clone()));
+                                    ui.close_menu();
+                                }
+                                if ui.button("Copy").clicked() {
+                                    self.clipboard = Some((entry.clone(), ClipOp::Copy));
+                                    ui.close_menu();
+                                }
+                                if ui.button("Cut").clicked() {
+                                    self.clipboard = Some((entry.clone(), ClipOp::Move));
+                                    ui.close_menu();
+                                }
+                            });
                         }
 
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -245,7 +943,28 @@ impl eframe::App for FileExplorer {
     }
 }
 
+// Parse `--vroot <DIR>`, resolving it without touching the filesystem so the
+// sandbox boundary can be set even if the directory doesn't exist yet.
+fn parse_vroot_arg() -> Option<PathBuf> {
+    let args: Vec<String> = std::env::args().collect();
+    let idx = args.iter().position(|arg| arg == "--vroot")?;
+    let dir = args.get(idx + 1).unwrap_or_else(|| {
+        eprintln!("--vroot requires a directory argument");
+        std::process::exit(1);
+    });
+
+    match PathBuf::from(dir).absolutize() {
+        Ok(resolved) => Some(resolved.into_owned()),
+        Err(e) => {
+            eprintln!("Invalid --vroot path {:?}: {}", dir, e);
+            None
+        }
+    }
+}
+
 fn main() -> eframe::Result<()> {
+    let virtual_root = parse_vroot_arg();
+
     let native_options = eframe::NativeOptions {
         initial_window_size: Some(egui::vec2(800.0, 600.0)),
         ..Default::default()
@@ -253,6 +972,15 @@ fn main() -> eframe::Result<()> {
     eframe::run_native(
         "File Explorer",
         native_options,
-        Box::new(|_cc| Box::new(FileExplorer::default())),
+        Box::new(move |cc| {
+            let mut app = FileExplorer::default();
+            if let Some(root) = virtual_root {
+                app.virtual_root = Some(root.clone());
+                app.current_path = root;
+                app.refresh_entries();
+            }
+            app.watch_current_path(&cc.egui_ctx);
+            Box::new(app)
+        }),
     )
 }